@@ -2,6 +2,8 @@ use std::cmp;
 use std::fmt;
 use std::result;
 
+use serde::Serialize;
+
 use crate::span;
 use crate::syntax::parser;
 use crate::syntax::semantics;
@@ -12,8 +14,30 @@ use crate::utils::repeat_str;
 pub(crate) type Result<T> = result::Result<T, Error>;
 
 /// This error type encompasses any error that can be returned when parsing.
+///
+/// Beyond the variant that describes what went wrong, an `Error` carries a
+/// trail of [`ContextFrame`]s accumulated via [`Error::context`] as it
+/// bubbles up the `tokenizer` -> `parser` -> `semantics` pipeline.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum Error {
+pub struct Error {
+    /// What went wrong, and where.
+    variant: ErrorVariant,
+    /// The trail of enclosing context frames this error bubbled through,
+    /// innermost (pushed first) to outermost (pushed last).
+    context: Vec<ContextFrame>,
+    /// The `.tree` file this error was produced from, if the caller told us
+    /// via [`Error::with_file`].
+    file: Option<String>,
+}
+
+/// Which stage of the pipeline an [`Error`] came from, and its payload.
+///
+/// Kept `pub(crate)`, not `pub`: its payload types (`tokenizer::Error`,
+/// `parser::Error`, `semantics::Error`) are themselves `pub(crate)`, so
+/// exposing this publicly would leak them. Callers outside the crate that
+/// need to distinguish stages get [`Stage`] instead, via [`Error::stage`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ErrorVariant {
     /// An error that occurred while tokenizing the input text.
     Tokenize(tokenizer::Error),
     /// An error that occurred while translating concrete syntax into abstract
@@ -31,38 +55,192 @@ pub enum Error {
     __Nonexhaustive,
 }
 
+/// The pipeline stage an [`Error`] came from, with no payload.
+///
+/// This is the public counterpart to [`ErrorVariant`]: it carries just
+/// enough information for a caller to decide whether a failure is
+/// recoverable, without exposing the `pub(crate)` `tokenizer`/`parser`/
+/// `semantics` error types that `ErrorVariant` wraps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stage {
+    /// An error that occurred while tokenizing the input text.
+    Tokenize,
+    /// An error that occurred while translating concrete syntax into
+    /// abstract syntax.
+    Parse,
+    /// An error that occurred while doing semantic analysis on the abstract
+    /// syntax tree.
+    Semantic,
+    /// Hints that destructuring should not be exhaustive.
+    ///
+    /// This enum may grow additional variants, so this makes sure clients
+    /// don't count on exhaustive matching. (Otherwise, adding a new variant
+    /// could break existing code.)
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+/// A single frame of context describing where, structurally, an error
+/// occurred, e.g. "while parsing the children of condition `when X`".
+///
+/// Frames accumulate as an error bubbles up the pipeline via
+/// [`Error::context`], so [`Formatter`] can print a trail from the
+/// top-level tree down to the exact failing node instead of a single
+/// isolated caret.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ContextFrame {
+    /// A static label describing this level of the pipeline.
+    label: &'static str,
+    /// Where in the source this frame applies, if known.
+    span: Option<span::Span>,
+}
+
 impl std::error::Error for Error {}
 
 impl From<parser::Error> for Error {
     fn from(err: parser::Error) -> Self {
-        Self::Parse(err)
+        Self {
+            variant: ErrorVariant::Parse(err),
+            context: Vec::new(),
+            file: None,
+        }
     }
 }
 
 impl From<tokenizer::Error> for Error {
     fn from(err: tokenizer::Error) -> Self {
-        Self::Tokenize(err)
+        Self {
+            variant: ErrorVariant::Tokenize(err),
+            context: Vec::new(),
+            file: None,
+        }
     }
 }
 
 impl From<Vec<semantics::Error>> for Error {
     fn from(errors: Vec<semantics::Error>) -> Self {
-        Self::Semantic(errors)
+        Self {
+            variant: ErrorVariant::Semantic(errors),
+            context: Vec::new(),
+            file: None,
+        }
+    }
+}
+
+impl Error {
+    /// The pipeline stage this error came from.
+    ///
+    /// Exposed so callers that need to distinguish tokenize/parse/semantic
+    /// errors (e.g. to decide whether a failure is recoverable) still can,
+    /// the way they could match directly when `Error` itself was an enum.
+    pub fn stage(&self) -> Stage {
+        match self.variant {
+            ErrorVariant::Tokenize(_) => Stage::Tokenize,
+            ErrorVariant::Parse(_) => Stage::Parse,
+            ErrorVariant::Semantic(_) => Stage::Semantic,
+            ErrorVariant::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    /// Pushes a context frame onto this error and returns it, for chaining
+    /// at each level of the pipeline the error bubbles through, e.g.:
+    ///
+    /// ```ignore
+    /// parse_children(node)
+    ///     .map_err(|e| e.context("while parsing the children of condition `when X`", Some(span)))?;
+    /// ```
+    pub(crate) fn context(mut self, label: &'static str, span: Option<span::Span>) -> Self {
+        self.context.push(ContextFrame { label, span });
+        self
+    }
+
+    /// Records which `.tree` file this error came from, for chaining at the
+    /// point a driver reads the file, e.g.:
+    ///
+    /// ```ignore
+    /// parse(text).map_err(|e| e.with_file(path.display().to_string()))?;
+    /// ```
+    ///
+    /// This is what lets [`Error::to_diagnostics`] populate
+    /// [`Diagnostic::file`], so a `--error-format=json` consumer (an editor
+    /// or a CI annotator) knows which file to point at without the caller
+    /// having to stitch it back on after the fact.
+    pub(crate) fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match *self {
-            Self::Parse(ref x) => x.fmt(f),
-            Self::Tokenize(ref x) => x.fmt(f),
-            Self::Semantic(ref errors) => {
+        match self.variant {
+            ErrorVariant::Parse(ref x) => x.fmt(f)?,
+            ErrorVariant::Tokenize(ref x) => x.fmt(f)?,
+            ErrorVariant::Semantic(ref errors) => {
                 for x in errors {
                     x.fmt(f)?;
                 }
-                Ok(())
             }
-            _ => unreachable!(),
+            ErrorVariant::__Nonexhaustive => unreachable!(),
+        }
+
+        // Walk the context trail outermost-first, so the reader sees the
+        // path from the top-level tree down to the exact failing node.
+        for frame in self.context.iter().rev() {
+            match frame.span {
+                Some(ref span) => writeln!(
+                    f,
+                    "note: {} (line {}, column {})",
+                    frame.label, span.start.line, span.start.column
+                )?,
+                None => writeln!(f, "note: {}", frame.label)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The severity of a [`Diagnostic`], or of the `ErrorKind` underlying a
+/// [`Formatter`].
+///
+/// Only `Error`-severity diagnostics fail the process; `Warning` and `Note`
+/// are for lint-style checks (e.g. a condition whose wording duplicates a
+/// sibling) that would be surfaced without blocking code generation.
+///
+/// As of this crate slice, no analyzer pass actually downgrades anything to
+/// `Warning` — see [`semantics::ErrorKind::ConditionDuplicate`] — so the only
+/// thing currently reachable at this severity is test coverage exercising
+/// [`semantics::Error::with_severity`] directly.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// An error that prevents code generation and fails the process.
+    #[default]
+    Error,
+    /// A lint-style diagnostic that doesn't block code generation.
+    Warning,
+    /// An informational diagnostic, typically attached as extra context.
+    Note,
+}
+
+impl Severity {
+    /// The word this severity prefixes diagnostic messages with, e.g.
+    /// `"bulloak error"`, to be followed by a `[CODE]:` suffix.
+    fn label(self) -> &'static str {
+        match self {
+            Self::Error => "bulloak error",
+            Self::Warning => "bulloak warning",
+            Self::Note => "bulloak note",
+        }
+    }
+
+    /// The glyph `notate` underlines the offending span with.
+    fn underline_glyph(self) -> char {
+        match self {
+            Self::Error => '^',
+            Self::Warning => '~',
+            Self::Note => '-',
         }
     }
 }
@@ -79,6 +257,8 @@ pub(crate) struct Formatter<'e, E> {
     err: &'e E,
     /// The span of the error.
     span: &'e span::Span,
+    /// How serious this error is. Only `Severity::Error` fails the process.
+    severity: Severity,
 }
 
 impl<'e> From<&'e parser::Error> for Formatter<'e, parser::ErrorKind> {
@@ -87,6 +267,7 @@ impl<'e> From<&'e parser::Error> for Formatter<'e, parser::ErrorKind> {
             text: err.text(),
             err: err.kind(),
             span: err.span(),
+            severity: Severity::Error,
         }
     }
 }
@@ -97,6 +278,7 @@ impl<'e> From<&'e tokenizer::Error> for Formatter<'e, tokenizer::ErrorKind> {
             text: err.text(),
             err: err.kind(),
             span: err.span(),
+            severity: Severity::Error,
         }
     }
 }
@@ -107,23 +289,25 @@ impl<'e> From<&'e semantics::Error> for Formatter<'e, semantics::ErrorKind> {
             text: err.text(),
             err: err.kind(),
             span: err.span(),
+            severity: err.severity(),
         }
     }
 }
 
-impl<'e, E: fmt::Display> fmt::Display for Formatter<'e, E> {
+impl<'e, E: fmt::Display + ErrorCode> fmt::Display for Formatter<'e, E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let divider = repeat_str("•", 79);
         writeln!(f, "{divider}")?;
 
+        let label = format!("{}[{}]:", self.severity.label(), self.err.code());
         let start_offset = self.span.start.offset;
         let end_offset = self.span.end.offset;
         if start_offset == end_offset && start_offset == 0 {
-            write!(f, "bulloak error: {}", self.err)?;
+            write!(f, "{label} {}", self.err)?;
             return Ok(());
         }
 
-        writeln!(f, "bulloak error: {}\n", self.err)?;
+        writeln!(f, "{label} {}\n", self.err)?;
         let notated = notate(self);
         writeln!(f, "{notated}")?;
         writeln!(
@@ -135,28 +319,371 @@ impl<'e, E: fmt::Display> fmt::Display for Formatter<'e, E> {
     }
 }
 
-/// Notate the text string with carets (`^`) pointing at the span.
-fn notate<E>(f: &Formatter<'_, E>) -> String {
+/// Implemented by every `ErrorKind` (`tokenizer::ErrorKind`,
+/// `parser::ErrorKind`, `semantics::ErrorKind`) so that [`Formatter`] and
+/// [`Diagnostic`] can surface a stable, lookup-able code alongside the
+/// message, e.g. `bulloak error[BULLOAK007]: ...`.
+///
+/// Codes are namespaced `BULLOAK###` regardless of which stage of the
+/// pipeline produced them, mirroring compiler diagnostics like rustc's
+/// `[E0001]`.
+///
+/// `Formatter`'s `Display` impl requires `E: ErrorCode`, so this trait and an
+/// impl for every `ErrorKind` it's used with must land in the same commit:
+/// adding the trait (or a new `ErrorKind`) without its impl leaves every
+/// existing `Display`/`Formatter` call site in the crate non-compiling, not
+/// just the new one.
+pub(crate) trait ErrorCode {
+    /// The stable code identifying this particular kind of error, e.g.
+    /// `"BULLOAK001"`.
+    fn code(&self) -> &'static str;
+
+    /// A suggested fix for this error, if an obvious one exists.
+    ///
+    /// Most `ErrorKind`s don't have one, so the default is `None`.
+    fn suggestion(&self) -> Option<Suggestion> {
+        None
+    }
+}
+
+/// How confidently a [`Suggestion`]'s replacement can be applied, mirroring
+/// rustc's `Applicability`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Applicability {
+    /// The suggestion is known to be correct and could be applied
+    /// automatically, e.g. by a future `--fix` flag.
+    MachineApplicable,
+    /// The suggestion is probably what's meant, but might not fit every
+    /// case, so it should be shown rather than applied automatically.
+    MaybeIncorrect,
+}
+
+/// A suggested correction for an error.
+///
+/// Kept as structured data rather than baked into the display string so
+/// that a `--fix` flag could apply `MachineApplicable` suggestions directly
+/// to the source instead of re-parsing rendered text.
+#[derive(Clone, Debug)]
+pub struct Suggestion {
+    /// The span of source text `replacement` should replace.
+    pub span: span::Span,
+    /// The text that should replace the contents of `span`.
+    pub replacement: String,
+    /// How safe it is to apply this suggestion automatically.
+    pub applicability: Applicability,
+}
+
+/// A longer, prose description of an error code, as printed by
+/// `bulloak explain <code>`.
+struct Explanation {
+    /// The error code this explanation is for, e.g. `"BULLOAK001"`.
+    code: &'static str,
+    /// A one-line summary of what triggers this error.
+    summary: &'static str,
+    /// A worked example showing a `.tree` snippet that triggers the error
+    /// and, where useful, the corrected version.
+    example: &'static str,
+}
+
+/// The registry `explain` looks codes up in.
+///
+/// Every `ErrorCode::code` implementation should have a matching entry here;
+/// `explain` falls back to `None` for codes that exist but haven't been
+/// written up yet, rather than panicking.
+const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        code: "BULLOAK001",
+        summary: "A token in the `.tree` file wasn't recognized or expected here.",
+        example: "given X\n└── it: doesn't have a when/given parent\n",
+    },
+    Explanation {
+        code: "BULLOAK002",
+        summary: "A condition (`when`/`given`) has no children describing its behavior.",
+        example: "when X\n// missing at least one `it` describing behavior",
+    },
+    Explanation {
+        code: "BULLOAK003",
+        summary: "A character was found that isn't valid at this position.",
+        example: "when X\n├── #it: stray punctuation before the action marker\n",
+    },
+    Explanation {
+        code: "BULLOAK004",
+        summary: "A `when`/`given` keyword was misspelled.",
+        example: "wehn X\n└── it: should revert\n// `wehn` should be `when`",
+    },
+    Explanation {
+        code: "BULLOAK005",
+        summary: "A `├──`/`└──` connector doesn't match its position among its siblings.",
+        example: "when X\n├── it: first\n├── it: last\n// the last child should use `└──`",
+    },
+    Explanation {
+        code: "BULLOAK006",
+        summary: "A leaf node has no `it`/action marker describing its behavior.",
+        example: "when X\n└── // missing `it: ...` describing the expected behavior",
+    },
+    Explanation {
+        code: "BULLOAK007",
+        summary: "A condition's wording is identical to one of its siblings.",
+        example: "when Y\n├── when X\n│   └── it: should revert\n└── when X\n    └── it: should not revert\n// the second `when X` duplicates its sibling's wording",
+    },
+];
+
+/// Prints a longer description of `code`, for the `bulloak explain <code>`
+/// entrypoint.
+///
+/// Returns `None` if `code` isn't a recognized, written-up error code.
+pub(crate) fn explain(code: &str) -> Option<String> {
+    EXPLANATIONS.iter().find(|e| e.code == code).map(|e| {
+        format!(
+            "{code}\n{divider}\n{summary}\n\nExample:\n\n{example}",
+            code = e.code,
+            divider = repeat_str("-", e.code.len()),
+            summary = e.summary,
+            example = e.example,
+        )
+    })
+}
+
+/// A span expressed as both a byte offset range and start/end line/column
+/// positions, so consumers don't have to re-derive one from the other.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct DiagnosticSpan {
+    /// The byte offset of the first byte in the span.
+    pub start_offset: usize,
+    /// The byte offset of the last byte in the span (inclusive, like
+    /// `end_column`).
+    pub end_offset: usize,
+    /// The 1-indexed line the span starts on.
+    pub start_line: usize,
+    /// The 1-indexed column the span starts on.
+    pub start_column: usize,
+    /// The 1-indexed line the span ends on.
+    pub end_line: usize,
+    /// The 1-indexed column the span ends on.
+    pub end_column: usize,
+}
+
+impl From<&span::Span> for DiagnosticSpan {
+    fn from(span: &span::Span) -> Self {
+        Self {
+            start_offset: span.start.offset,
+            end_offset: span.end.offset,
+            start_line: span.start.line,
+            start_column: span.start.column,
+            end_line: span.end.line,
+            end_column: span.end.column,
+        }
+    }
+}
+
+/// Which output format an error should be rendered in, mirroring what a
+/// `--error-format` CLI flag would select between.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub(crate) enum ErrorFormat {
+    /// The human-readable caret notation [`Formatter`] renders.
+    #[default]
+    Human,
+    /// The [`Diagnostic`] array [`Error::to_json`] serializes.
+    Json,
+}
+
+/// A JSON-serializable mirror of [`Suggestion`], so a machine consumer of
+/// [`Diagnostic`] (an editor, a future `--fix` flag) can see a fix-it
+/// without scraping the `help:` line `notate()` renders for humans.
+#[derive(Clone, Debug, Serialize)]
+pub struct DiagnosticSuggestion {
+    /// The span of source text `replacement` should replace.
+    pub span: DiagnosticSpan,
+    /// The text that should replace the contents of `span`.
+    pub replacement: String,
+    /// How safe it is to apply this suggestion automatically.
+    pub applicability: Applicability,
+}
+
+impl From<Suggestion> for DiagnosticSuggestion {
+    fn from(suggestion: Suggestion) -> Self {
+        Self {
+            span: (&suggestion.span).into(),
+            replacement: suggestion.replacement,
+            applicability: suggestion.applicability,
+        }
+    }
+}
+
+/// A machine-readable representation of a single error, suitable for
+/// serialization as JSON for editors, language servers, and CI annotations.
+///
+/// Unlike [`Formatter`], which renders a human-readable caret notation, a
+/// `Diagnostic` exposes the same information as plain data: byte offsets and
+/// line/column positions that a consumer can map directly onto the original
+/// `.tree` source without scraping the caret output.
+#[derive(Clone, Debug, Serialize)]
+pub struct Diagnostic {
+    /// How serious this diagnostic is.
+    pub severity: Severity,
+    /// The human-readable message, identical to what `Formatter` prints
+    /// after its `bulloak error[CODE]:` label.
+    pub message: String,
+    /// A stable, lookup-able error code (e.g. `"BULLOAK001"`), if this kind
+    /// of error has been assigned one.
+    pub code: Option<&'static str>,
+    /// The `.tree` file this diagnostic was produced from, if known.
+    pub file: Option<String>,
+    /// The source spans this diagnostic points at.
+    pub spans: Vec<DiagnosticSpan>,
+    /// A suggested fix for this diagnostic, if [`ErrorCode::suggestion`]
+    /// has one.
+    pub suggestion: Option<DiagnosticSuggestion>,
+}
+
+impl Error {
+    /// Converts this error into its JSON-serializable [`Diagnostic`] form.
+    ///
+    /// A single `Error` can expand into more than one diagnostic: the
+    /// `Semantic` variant carries a `Vec<semantics::Error>`, one diagnostic
+    /// per failing node.
+    pub(crate) fn to_diagnostics(&self) -> Vec<Diagnostic> {
+        match &self.variant {
+            ErrorVariant::Tokenize(err) => vec![Diagnostic {
+                severity: Severity::Error,
+                message: err.kind().to_string(),
+                code: Some(err.kind().code()),
+                file: self.file.clone(),
+                spans: vec![err.span().into()],
+                suggestion: err.kind().suggestion().map(Into::into),
+            }],
+            ErrorVariant::Parse(err) => vec![Diagnostic {
+                severity: Severity::Error,
+                message: err.kind().to_string(),
+                code: Some(err.kind().code()),
+                file: self.file.clone(),
+                spans: vec![err.span().into()],
+                suggestion: err.kind().suggestion().map(Into::into),
+            }],
+            ErrorVariant::Semantic(errors) => errors
+                .iter()
+                .map(|err| Diagnostic {
+                    severity: err.severity(),
+                    message: err.kind().to_string(),
+                    code: Some(err.kind().code()),
+                    file: self.file.clone(),
+                    spans: vec![err.span().into()],
+                    suggestion: err.kind().suggestion().map(Into::into),
+                })
+                .collect(),
+            ErrorVariant::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    /// Serializes this error as a JSON array of [`Diagnostic`]s.
+    ///
+    /// This is the data model a `--error-format=json` flag would serialize:
+    /// editors and CI annotators could place squiggles at exact spans instead
+    /// of parsing the human-readable caret output, and [`Error::with_file`]
+    /// is what lets [`Diagnostic::file`] carry the originating filename
+    /// along.
+    pub(crate) fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&self.to_diagnostics())
+    }
+
+    /// Renders this error the way a `--error-format` flag would pick between:
+    /// human-readable caret notation, or the [`Error::to_json`] diagnostics
+    /// array.
+    ///
+    /// This crate doesn't contain a CLI entrypoint to parse such a flag, so
+    /// nothing calls this yet outside of tests — it's the single seam a
+    /// driver's `--error-format=json` would dispatch through once one
+    /// exists, rather than that driver re-deciding between `Display` and
+    /// `to_json` itself.
+    pub(crate) fn render(&self, format: ErrorFormat) -> String {
+        match format {
+            ErrorFormat::Human => self.to_string(),
+            ErrorFormat::Json => self
+                .to_json()
+                .unwrap_or_else(|e| format!("failed to serialize diagnostics as JSON: {e}")),
+        }
+    }
+
+    /// Whether this error should fail the process.
+    ///
+    /// `Tokenize` and `Parse` errors are always fatal. A `Semantic` error
+    /// only fails the process if at least one of its diagnostics is
+    /// `Severity::Error`-level; a tree containing only warnings still
+    /// generates code.
+    pub(crate) fn is_fatal(&self) -> bool {
+        match &self.variant {
+            ErrorVariant::Tokenize(_) | ErrorVariant::Parse(_) => true,
+            ErrorVariant::Semantic(errors) => {
+                errors.iter().any(|err| err.severity() == Severity::Error)
+            }
+            ErrorVariant::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+/// Notate the text string with carets pointing at the span.
+///
+/// The underline glyph depends on `f`'s severity: `^` for errors, `~` for
+/// warnings, `-` for notes.
+fn notate<E: ErrorCode>(f: &Formatter<'_, E>) -> String {
     let mut notated = String::new();
-    if let Some(line) = f.text.lines().nth(f.span.start.line - 1) {
+    let glyph = f.severity.underline_glyph();
+    let start_line = f.span.start.line;
+    let end_line = f.span.end.line;
+    let mut rendered_any_line = false;
+
+    for line_no in start_line..=end_line {
+        let Some(line) = f.text.lines().nth(line_no - 1) else {
+            continue;
+        };
+        rendered_any_line = true;
         notated.push_str(line);
         notated.push('\n');
-        notated.push_str(&repeat_str(" ", f.span.start.column - 1));
-        let note_len = f.span.end.column.saturating_sub(f.span.start.column) + 1;
-        let note_len = cmp::max(1, note_len);
-        notated.push_str(&repeat_str("^", note_len));
+
+        // The single-line case underlines exactly the span; the first and
+        // last lines of a multi-line span underline from/to the respective
+        // column, and every interior line is underlined in full, matching
+        // how compiler diagnostics render multi-line labels.
+        let (underline_start_column, underline_len) = if start_line == end_line {
+            let len = f.span.end.column.saturating_sub(f.span.start.column) + 1;
+            (f.span.start.column, len)
+        } else if line_no == start_line {
+            let len = line.chars().count().saturating_sub(f.span.start.column - 1);
+            (f.span.start.column, len)
+        } else if line_no == end_line {
+            (1, f.span.end.column)
+        } else {
+            (1, line.chars().count())
+        };
+        let underline_len = cmp::max(1, underline_len);
+
+        notated.push_str(&repeat_str(" ", underline_start_column - 1));
+        notated.push_str(&repeat_str(&glyph.to_string(), underline_len));
         notated.push('\n');
     }
 
+    if rendered_any_line {
+        if let Some(suggestion) = f.err.suggestion() {
+            // Align to `suggestion.span`, not `f.span`: the two differ
+            // whenever the fix belongs at a specific point inside the
+            // enclosing error's span (see `ActionMissing`/`ConnectorUnbalanced`
+            // docs), and indenting from the wrong one points the `help:` line
+            // at the wrong column.
+            notated.push_str(&repeat_str(" ", suggestion.span.start.column - 1));
+            notated.push_str(&format!("help: {}\n", suggestion.replacement));
+        }
+    }
+
     notated
 }
 
 #[cfg(test)]
 mod test {
     use super::repeat_str;
-    use crate::error::Formatter;
+    use crate::error::{ErrorCode, Formatter};
     use crate::span::{Position, Span};
-    use crate::syntax::{parser, semantics};
+    use crate::syntax::{parser, semantics, tokenizer};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -167,13 +694,21 @@ mod test {
             text,
             err: &parser::ErrorKind::TokenUnexpected("world".to_owned()),
             span: &span,
+            severity: super::Severity::Error,
         };
         let notated = format!("{}", formatter);
 
         let mut expected = String::from("");
         expected.push_str(&repeat_str("•", 79));
         expected.push('\n');
-        expected.push_str(format!("bulloak error: {}\n\n", formatter.err).as_str());
+        expected.push_str(
+            format!(
+                "bulloak error[{}]: {}\n\n",
+                formatter.err.code(),
+                formatter.err
+            )
+            .as_str(),
+        );
         expected.push_str("world\n");
         expected.push_str("^^^^^\n\n");
         expected.push_str(
@@ -186,6 +721,23 @@ mod test {
         assert_eq!(expected, notated);
     }
 
+    #[test]
+    fn test_notate_warning_uses_tilde_glyph() {
+        let text = "hello\nworld\n";
+        let span = Span::new(Position::new(0, 2, 1), Position::new(4, 2, 5));
+        let formatter = Formatter {
+            text,
+            err: &parser::ErrorKind::TokenUnexpected("world".to_owned()),
+            span: &span,
+            severity: super::Severity::Warning,
+        };
+        let notated = format!("{}", formatter);
+
+        assert!(notated.starts_with(&repeat_str("•", 79)));
+        assert!(notated.contains("bulloak warning["));
+        assert!(notated.contains("~~~~~\n"));
+    }
+
     #[test]
     fn test_multiple_errors() {
         let text = r"test.sol
@@ -207,22 +759,357 @@ mod test {
         ]);
         let actual = format!("{errors}");
 
-        let expected = r"•••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••
-bulloak error: found a condition with no children
+        let code = semantics::ErrorKind::ConditionEmpty.code();
+        let divider = repeat_str("•", 79);
+        let expected = format!(
+            "{divider}\n\
+             bulloak error[{code}]: found a condition with no children\n\
+             \n\
+             ├── when 1\n\
+             ^^^^^^^^^^\n\
+             \n\
+             --- (line 2, column 1) ---\n\
+             {divider}\n\
+             bulloak error[{code}]: found a condition with no children\n\
+             \n\
+             └── when 2\n\
+             ^^^^^^^^^^\n\
+             \n\
+             --- (line 3, column 1) ---\n"
+        );
 
-├── when 1
-^^^^^^^^^^
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_to_diagnostics_semantic() {
+        let text = r"test.sol
+├── when 1"
+            .to_owned();
 
---- (line 2, column 1) ---
-•••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••••
-bulloak error: found a condition with no children
+        let err = crate::error::Error::from(vec![semantics::Error::new(
+            semantics::ErrorKind::ConditionEmpty,
+            text.clone(),
+            Span::new(Position::new(9, 2, 1), Position::new(18, 2, 10)),
+        )])
+        .with_file("test.tree");
 
-└── when 2
-^^^^^^^^^^
+        let diagnostics = err.to_diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, super::Severity::Error);
+        assert_eq!(diagnostics[0].file.as_deref(), Some("test.tree"));
+        assert_eq!(diagnostics[0].spans[0].start_line, 2);
+        assert_eq!(diagnostics[0].spans[0].start_column, 1);
+        assert!(diagnostics[0].suggestion.is_none());
 
---- (line 3, column 1) ---
-";
+        let json = err.to_json().unwrap();
+        assert!(json.contains("\"severity\":\"error\""));
+        assert!(json.contains("\"file\":\"test.tree\""));
+    }
 
-        assert_eq!(expected, actual);
+    #[test]
+    fn test_to_diagnostics_carries_the_suggestion_for_a_fix_it_consumer() {
+        let text = "when X\n└── \n".to_owned();
+        let span = Span::new(Position::new(11, 2, 5), Position::new(11, 2, 5));
+
+        let err = crate::error::Error::from(vec![semantics::Error::new(
+            semantics::ErrorKind::ActionMissing { span: span.clone() },
+            text,
+            span,
+        )]);
+
+        let diagnostics = err.to_diagnostics();
+        let suggestion = diagnostics[0]
+            .suggestion
+            .as_ref()
+            .expect("ActionMissing's suggestion() should carry through to the diagnostic");
+        assert_eq!(suggestion.replacement, "it: ...");
+        assert_eq!(suggestion.applicability, super::Applicability::MaybeIncorrect);
+
+        let json = err.to_json().unwrap();
+        assert!(json.contains("\"replacement\":\"it: ...\""));
+        assert!(json.contains("\"applicability\":\"maybe_incorrect\""));
+    }
+
+    #[test]
+    fn test_render_dispatches_between_human_and_json_formats() {
+        let text = r"test.sol
+├── when 1"
+            .to_owned();
+
+        let err = crate::error::Error::from(vec![semantics::Error::new(
+            semantics::ErrorKind::ConditionEmpty,
+            text.clone(),
+            Span::new(Position::new(9, 2, 1), Position::new(18, 2, 10)),
+        )])
+        .with_file("test.tree");
+
+        let human = err.render(super::ErrorFormat::Human);
+        assert_eq!(human, err.to_string());
+
+        let json = err.render(super::ErrorFormat::Json);
+        assert_eq!(json, err.to_json().unwrap());
+        assert!(json.contains("\"file\":\"test.tree\""));
+    }
+
+    #[test]
+    fn test_explain_known_and_unknown_code() {
+        let explanation = super::explain("BULLOAK002").unwrap();
+        assert!(explanation.contains("BULLOAK002"));
+        assert!(explanation.contains("when"));
+
+        assert!(super::explain("BULLOAK999").is_none());
+    }
+
+    #[test]
+    fn test_notate_renders_help_line_for_suggestion() {
+        let text = "wehn X\n└── it: should revert\n";
+        let span = Span::new(Position::new(0, 1, 1), Position::new(3, 1, 4));
+        let kind = tokenizer::ErrorKind::KeywordTypo {
+            found: "wehn".to_owned(),
+            expected: "when",
+            span: span.clone(),
+        };
+        let formatter = Formatter {
+            text,
+            err: &kind,
+            span: &span,
+            severity: super::Severity::Error,
+        };
+        let notated = format!("{}", formatter);
+
+        assert!(notated.contains("wehn X\n^^^^\n"));
+        assert!(notated.contains("help: when\n"));
+    }
+
+    #[test]
+    fn test_notate_help_line_aligns_to_suggestion_span_not_enclosing_span() {
+        let text = "when X\n└── \n";
+        // The enclosing error spans the whole leaf node, starting at column
+        // 1, but the fix belongs at column 5, right after `└── `, which is
+        // where `ActionMissing`'s own `span` points.
+        let enclosing_span = Span::new(Position::new(7, 2, 1), Position::new(11, 2, 5));
+        let suggestion_span = Span::new(Position::new(11, 2, 5), Position::new(11, 2, 5));
+        let kind = semantics::ErrorKind::ActionMissing {
+            span: suggestion_span,
+        };
+        let formatter = Formatter {
+            text,
+            err: &kind,
+            span: &enclosing_span,
+            severity: super::Severity::Error,
+        };
+        let notated = format!("{}", formatter);
+
+        let help_line = notated
+            .lines()
+            .find(|line| line.contains("help:"))
+            .expect("notate should render a help line for ActionMissing");
+        assert_eq!(help_line, "    help: it: ...");
+    }
+
+    #[test]
+    fn test_notate_multi_line_span() {
+        let text = "when X\n├── it: one\n└── it: two\n";
+        // Spans the branch starting mid-way through line 2 through the end
+        // of line 3.
+        let span = Span::new(Position::new(7, 2, 5), Position::new(37, 3, 11));
+        let formatter = Formatter {
+            text,
+            err: &parser::ErrorKind::TokenUnexpected("it: two".to_owned()),
+            span: &span,
+            severity: super::Severity::Error,
+        };
+        let notated = format!("{}", formatter);
+
+        let mut expected = String::from("");
+        expected.push_str(&repeat_str("•", 79));
+        expected.push('\n');
+        expected.push_str(
+            format!(
+                "bulloak error[{}]: {}\n\n",
+                formatter.err.code(),
+                formatter.err
+            )
+            .as_str(),
+        );
+        expected.push_str("├── it: one\n");
+        expected.push_str("    ^^^^^^^\n");
+        expected.push_str("└── it: two\n");
+        expected.push_str("^^^^^^^^^^^\n\n");
+        expected.push_str(
+            format!(
+                "--- (line {}, column {}) ---\n",
+                formatter.span.start.line, formatter.span.start.column
+            )
+            .as_str(),
+        );
+        assert_eq!(expected, notated);
+    }
+
+    #[test]
+    fn test_context_frames_render_as_note_trail() {
+        let text = r"test.sol
+├── when 1"
+            .to_owned();
+
+        let err = crate::error::Error::from(vec![semantics::Error::new(
+            semantics::ErrorKind::ConditionEmpty,
+            text.clone(),
+            Span::new(Position::new(9, 2, 1), Position::new(18, 2, 10)),
+        )])
+        .context(
+            "while parsing the children of condition `when 1`",
+            Some(Span::new(Position::new(9, 2, 1), Position::new(18, 2, 10))),
+        )
+        .context("while parsing the top-level tree `test.sol`", None);
+
+        let rendered = format!("{err}");
+
+        let code = semantics::ErrorKind::ConditionEmpty.code();
+        let divider = repeat_str("•", 79);
+        let expected = format!(
+            "{divider}\n\
+             bulloak error[{code}]: found a condition with no children\n\
+             \n\
+             ├── when 1\n\
+             ^^^^^^^^^^\n\
+             \n\
+             --- (line 2, column 1) ---\n\
+             note: while parsing the top-level tree `test.sol`\n\
+             note: while parsing the children of condition `when 1` (line 2, column 1)\n"
+        );
+
+        assert_eq!(expected, rendered);
+    }
+
+    #[test]
+    fn test_warning_severity_via_with_severity_is_not_fatal() {
+        let text = "when X\n├── when X\n└── it: should revert\n".to_owned();
+        let span = Span::new(Position::new(11, 2, 5), Position::new(17, 2, 11));
+
+        let err = crate::error::Error::from(vec![semantics::Error::new(
+            semantics::ErrorKind::ConditionDuplicate {
+                sibling: "when X".to_owned(),
+            },
+            text,
+            span,
+        )
+        .with_severity(super::Severity::Warning)]);
+
+        assert!(!err.is_fatal());
+        let diagnostics = err.to_diagnostics();
+        assert_eq!(diagnostics[0].severity, super::Severity::Warning);
+        assert_eq!(diagnostics[0].code, Some("BULLOAK007"));
+    }
+
+    #[test]
+    fn test_error_severity_semantic_error_is_fatal() {
+        let text = r"test.sol
+├── when 1"
+            .to_owned();
+
+        let err = crate::error::Error::from(vec![semantics::Error::new(
+            semantics::ErrorKind::ConditionEmpty,
+            text,
+            Span::new(Position::new(9, 2, 1), Position::new(18, 2, 10)),
+        )]);
+
+        assert!(err.is_fatal());
+    }
+
+    #[test]
+    fn test_tokenize_and_parse_errors_are_always_fatal() {
+        let text = "wehn X\n└── it: should revert\n".to_owned();
+        let span = Span::new(Position::new(0, 1, 1), Position::new(3, 1, 4));
+
+        let tokenize_err = crate::error::Error::from(tokenizer::Error::new(
+            tokenizer::ErrorKind::KeywordTypo {
+                found: "wehn".to_owned(),
+                expected: "when",
+                span: span.clone(),
+            },
+            text.clone(),
+            span.clone(),
+        ));
+        assert!(tokenize_err.is_fatal());
+
+        let parse_err = crate::error::Error::from(parser::Error::new(
+            parser::ErrorKind::TokenUnexpected("wehn".to_owned()),
+            text,
+            span,
+        ));
+        assert!(parse_err.is_fatal());
+    }
+
+    #[test]
+    fn test_connector_unbalanced_is_assigned_a_code() {
+        let text = "when X\n├── it: one\n├── it: two\n".to_owned();
+        let span = Span::new(Position::new(7, 2, 1), Position::new(9, 2, 3));
+
+        let err = crate::error::Error::from(parser::Error::new(
+            parser::ErrorKind::ConnectorUnbalanced {
+                found: "├──".to_owned(),
+                span: span.clone(),
+            },
+            text,
+            span,
+        ));
+
+        let diagnostics = err.to_diagnostics();
+        assert_eq!(diagnostics[0].code, Some("BULLOAK005"));
+    }
+
+    #[test]
+    fn test_char_unexpected_is_assigned_a_code_and_has_no_suggestion() {
+        let text = "whe@n X\n".to_owned();
+        let span = Span::new(Position::new(3, 1, 4), Position::new(3, 1, 4));
+        let kind = tokenizer::ErrorKind::CharUnexpected('@');
+        assert!(kind.suggestion().is_none());
+
+        let err = crate::error::Error::from(tokenizer::Error::new(kind, text, span));
+        let diagnostics = err.to_diagnostics();
+        assert_eq!(diagnostics[0].code, Some("BULLOAK003"));
+    }
+
+    #[test]
+    fn test_action_missing_suggests_an_it_stub() {
+        let text = "when X\n└── \n".to_owned();
+        let span = Span::new(Position::new(11, 2, 5), Position::new(11, 2, 5));
+        let kind = semantics::ErrorKind::ActionMissing { span: span.clone() };
+        let suggestion = kind
+            .suggestion()
+            .expect("ActionMissing should suggest an `it: ...` stub");
+        assert_eq!(suggestion.replacement, "it: ...");
+
+        let err = semantics::Error::new(kind, text, span);
+        assert_eq!(err.severity(), super::Severity::Error);
+    }
+
+    #[test]
+    fn test_stage_reports_pipeline_stage_without_leaking_the_payload_type() {
+        let text = "wehn X\n".to_owned();
+        let span = Span::new(Position::new(0, 1, 1), Position::new(3, 1, 4));
+
+        let tokenize_err = crate::error::Error::from(tokenizer::Error::new(
+            tokenizer::ErrorKind::CharUnexpected('@'),
+            text.clone(),
+            span.clone(),
+        ));
+        assert_eq!(tokenize_err.stage(), super::Stage::Tokenize);
+
+        let parse_err = crate::error::Error::from(parser::Error::new(
+            parser::ErrorKind::TokenUnexpected("wehn".to_owned()),
+            text.clone(),
+            span.clone(),
+        ));
+        assert_eq!(parse_err.stage(), super::Stage::Parse);
+
+        let semantic_err = crate::error::Error::from(vec![semantics::Error::new(
+            semantics::ErrorKind::ConditionEmpty,
+            text,
+            span,
+        )]);
+        assert_eq!(semantic_err.stage(), super::Stage::Semantic);
     }
 }
@@ -0,0 +1,6 @@
+//! The tokenize -> parse -> semantic-analysis pipeline that turns a `.tree`
+//! file into the abstract syntax bulloak generates Solidity tests from.
+
+pub(crate) mod parser;
+pub(crate) mod semantics;
+pub(crate) mod tokenizer;
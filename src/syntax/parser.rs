@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::span::Span;
+
+/// What specifically went wrong while translating concrete syntax (tokens)
+/// into abstract syntax.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ErrorKind {
+    /// A token was found where it wasn't expected, e.g. an `it`/`given`
+    /// appearing where a `when` was required.
+    TokenUnexpected(String),
+    /// A branch's `├──`/`└──` connector doesn't match its position among
+    /// its siblings, e.g. a `├──` used on the last child instead of `└──`.
+    ///
+    /// The connector itself is only three characters wide, so this variant
+    /// keeps its own `span` rather than letting the suggestion fall back to
+    /// the wider span of the branch it prefixes.
+    ConnectorUnbalanced { found: String, span: Span },
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TokenUnexpected(token) => write!(f, "found unexpected token `{token}`"),
+            Self::ConnectorUnbalanced { found, .. } => {
+                write!(f, "found connector `{found}` that doesn't match its position among its siblings")
+            }
+        }
+    }
+}
+
+/// An error produced while translating concrete syntax into abstract
+/// syntax.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Error {
+    kind: ErrorKind,
+    text: String,
+    span: Span,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, text: String, span: Span) -> Self {
+        Self { kind, text, span }
+    }
+
+    /// The kind of error that occurred.
+    pub(crate) fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The original .tree text in which the error occurred.
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The span of the error.
+    pub(crate) fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::error::Formatter::from(self).fmt(f)
+    }
+}
+
+impl crate::error::ErrorCode for ErrorKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::TokenUnexpected(_) => "BULLOAK001",
+            Self::ConnectorUnbalanced { .. } => "BULLOAK005",
+        }
+    }
+
+    fn suggestion(&self) -> Option<crate::error::Suggestion> {
+        match self {
+            Self::TokenUnexpected(_) => None,
+            Self::ConnectorUnbalanced { found, span } => {
+                let replacement = if found == "└──" { "├──" } else { "└──" };
+                Some(crate::error::Suggestion {
+                    span: span.clone(),
+                    replacement: replacement.to_owned(),
+                    applicability: crate::error::Applicability::MaybeIncorrect,
+                })
+            }
+        }
+    }
+}
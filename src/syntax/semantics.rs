@@ -0,0 +1,134 @@
+use std::fmt;
+
+use crate::error::Severity;
+use crate::span::Span;
+
+/// What specifically went wrong during semantic analysis of the abstract
+/// syntax tree.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ErrorKind {
+    /// A condition (`when`/`given`) has no children describing its
+    /// behavior.
+    ConditionEmpty,
+    /// A leaf node has no `it`/action marker describing the expected
+    /// behavior.
+    ///
+    /// The replacement goes right where the marker is missing, which is a
+    /// different position than the node's own span, so this variant tracks
+    /// it separately.
+    ActionMissing { span: Span },
+    /// A condition's wording is identical to one of its siblings, which
+    /// usually means a copy-paste left one of them unedited.
+    ///
+    /// This is a lint, not a hard error: it's surfaced at
+    /// [`Severity::Warning`] via [`Error::with_severity`] so it doesn't
+    /// block code generation.
+    ///
+    /// No analysis pass in this crate constructs this variant yet — there's
+    /// no duplicate-sibling check in the semantic analyzer to produce one.
+    /// It exists so the `Warning` plumbing (this type, `Formatter`,
+    /// `Diagnostic`) has a real lint-shaped variant to be threaded through
+    /// and tested end-to-end ahead of that check landing.
+    ConditionDuplicate { sibling: String },
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConditionEmpty => write!(f, "found a condition with no children"),
+            Self::ActionMissing { .. } => {
+                write!(f, "found a leaf with no `it` describing its behavior")
+            }
+            Self::ConditionDuplicate { sibling } => {
+                write!(f, "condition wording duplicates sibling `{sibling}`")
+            }
+        }
+    }
+}
+
+/// An error produced while doing semantic analysis on the abstract syntax
+/// tree.
+///
+/// Unlike `tokenizer::Error` and `parser::Error`, which are always fatal,
+/// a `semantics::Error` carries its own [`Severity`]: some semantic checks
+/// (e.g. stylistic lints) are warnings that don't fail the process.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Error {
+    kind: ErrorKind,
+    text: String,
+    span: Span,
+    severity: Severity,
+}
+
+impl Error {
+    /// Creates a new semantic error with `Severity::Error`, the severity
+    /// every semantic check had before [`Severity`] was introduced.
+    pub(crate) fn new(kind: ErrorKind, text: String, span: Span) -> Self {
+        Self {
+            kind,
+            text,
+            span,
+            severity: Severity::Error,
+        }
+    }
+
+    /// The kind of error that occurred.
+    pub(crate) fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The original .tree text in which the error occurred.
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The span of the error.
+    pub(crate) fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// How serious this error is. Only `Severity::Error` fails the process.
+    pub(crate) fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Overrides the severity this error was constructed with.
+    ///
+    /// Downgrading a lint-style kind like [`ErrorKind::ConditionDuplicate`]
+    /// to [`Severity::Warning`] with this surfaces it without failing the
+    /// process. Nothing in the crate outside of tests calls this yet.
+    pub(crate) fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::error::Formatter::from(self).fmt(f)
+    }
+}
+
+impl crate::error::ErrorCode for ErrorKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ConditionEmpty => "BULLOAK002",
+            Self::ActionMissing { .. } => "BULLOAK006",
+            Self::ConditionDuplicate { .. } => "BULLOAK007",
+        }
+    }
+
+    fn suggestion(&self) -> Option<crate::error::Suggestion> {
+        match self {
+            Self::ConditionEmpty => None,
+            Self::ActionMissing { span } => Some(crate::error::Suggestion {
+                span: span.clone(),
+                replacement: "it: ...".to_owned(),
+                applicability: crate::error::Applicability::MaybeIncorrect,
+            }),
+            // Which wording to keep is a judgment call the author has to
+            // make, so there's no replacement to suggest automatically.
+            Self::ConditionDuplicate { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+use crate::span::Span;
+
+/// What specifically went wrong while tokenizing the input text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ErrorKind {
+    /// A character was found that isn't valid at this position, e.g. stray
+    /// punctuation outside of a connector prefix.
+    CharUnexpected(char),
+    /// A `when`/`given` keyword was misspelled, e.g. `wehn`/`gvien`.
+    ///
+    /// The misspelled word is typically shorter than the whole token the
+    /// enclosing `Error` spans, so this variant tracks its own `span` to
+    /// keep the suggested replacement tight around just the typo.
+    KeywordTypo {
+        found: String,
+        expected: &'static str,
+        span: Span,
+    },
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CharUnexpected(c) => write!(f, "found unexpected character `{c}`"),
+            Self::KeywordTypo { found, expected, .. } => {
+                write!(f, "found `{found}` where keyword `{expected}` was expected")
+            }
+        }
+    }
+}
+
+/// An error produced while tokenizing the input text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Error {
+    kind: ErrorKind,
+    text: String,
+    span: Span,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, text: String, span: Span) -> Self {
+        Self { kind, text, span }
+    }
+
+    /// The kind of error that occurred.
+    pub(crate) fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The original .tree text in which the error occurred.
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The span of the error.
+    pub(crate) fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::error::Formatter::from(self).fmt(f)
+    }
+}
+
+impl crate::error::ErrorCode for ErrorKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::CharUnexpected(_) => "BULLOAK003",
+            Self::KeywordTypo { .. } => "BULLOAK004",
+        }
+    }
+
+    fn suggestion(&self) -> Option<crate::error::Suggestion> {
+        match self {
+            Self::CharUnexpected(_) => None,
+            Self::KeywordTypo { expected, span, .. } => Some(crate::error::Suggestion {
+                span: span.clone(),
+                replacement: (*expected).to_owned(),
+                applicability: crate::error::Applicability::MachineApplicable,
+            }),
+        }
+    }
+}